@@ -8,4 +8,16 @@ void get_ffmpeg_features(void* ctx, uint8_t all, feature_callback_t* cb) {
     if (all || LIBAVUTIL_VERSION_INT >= AV_VERSION_INT(57, 24, 0)) {
         cb(ctx, "channel_layout_v2");
     }
+
+    if (all || LIBAVUTIL_VERSION_INT >= AV_VERSION_INT(56, 45, 100)) {
+        cb(ctx, "video_enc_params");
+    }
+
+    if (all || LIBAVUTIL_VERSION_INT >= AV_VERSION_INT(57, 43, 100)) {
+        cb(ctx, "frame_duration");
+    }
+
+    if (all || LIBAVUTIL_VERSION_INT >= AV_VERSION_INT(58, 7, 100)) {
+        cb(ctx, "frame_interlace_flags");
+    }
 }
@@ -37,6 +37,15 @@ fn main() {
         build.define(&format!("FFW_FEATURE_{}", feature.to_uppercase()), None);
     }
 
+    // helpers used only by the tests are kept in a separate library, so they
+    // do not end up in the main one (the library is linked explicitly by the
+    // test modules)
+    build
+        .clone()
+        .file(src_codec_dir.join("frame_test.c"))
+        .cargo_metadata(false)
+        .compile("ffwrapper_test");
+
     build
         .file(src_dir.join("error.c"))
         .file(src_dir.join("logger.c"))
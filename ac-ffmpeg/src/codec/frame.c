@@ -6,6 +6,10 @@
 #include <libavutil/pixfmt.h>
 #include <libavutil/samplefmt.h>
 
+#ifdef FFW_FEATURE_VIDEO_ENC_PARAMS
+#include <libavutil/video_enc_params.h>
+#endif
+
 #ifdef FFW_FEATURE_CHANNEL_LAYOUT_V2
 
 int ffw_channel_layout_get_default(AVChannelLayout** layout, uint32_t channels) {
@@ -132,6 +136,38 @@ int ffw_pixel_format_is_none(int format) {
     return format == AV_PIX_FMT_NONE;
 }
 
+const char* ffw_get_color_range_name(int range) {
+    return av_color_range_name(range);
+}
+
+const char* ffw_get_color_primaries_name(int primaries) {
+    return av_color_primaries_name(primaries);
+}
+
+const char* ffw_get_color_transfer_name(int transfer) {
+    return av_color_transfer_name(transfer);
+}
+
+const char* ffw_get_color_space_name(int space) {
+    return av_color_space_name(space);
+}
+
+int ffw_get_color_range_by_name(const char* name) {
+    return av_color_range_from_name(name);
+}
+
+int ffw_get_color_primaries_by_name(const char* name) {
+    return av_color_primaries_from_name(name);
+}
+
+int ffw_get_color_transfer_by_name(const char* name) {
+    return av_color_transfer_from_name(name);
+}
+
+int ffw_get_color_space_by_name(const char* name) {
+    return av_color_space_from_name(name);
+}
+
 AVFrame* ffw_frame_new_black(int, int, int);
 void ffw_frame_free(AVFrame*);
 
@@ -279,6 +315,22 @@ void ffw_frame_set_pts(AVFrame* frame, int64_t pts) {
     frame->pts = pts;
 }
 
+int64_t ffw_frame_get_duration(const AVFrame* frame) {
+#ifdef FFW_FEATURE_FRAME_DURATION
+    return frame->duration;
+#else
+    return frame->pkt_duration;
+#endif
+}
+
+void ffw_frame_set_duration(AVFrame* frame, int64_t duration) {
+#ifdef FFW_FEATURE_FRAME_DURATION
+    frame->duration = duration;
+#else
+    frame->pkt_duration = duration;
+#endif
+}
+
 AVFrame* ffw_frame_clone(const AVFrame* frame) {
     return av_frame_clone(frame);
 }
@@ -315,6 +367,267 @@ int ffw_frame_make_writable(AVFrame* frame) {
     return av_frame_make_writable(frame);
 }
 
+int ffw_frame_get_color_range(const AVFrame* frame) {
+    return frame->color_range;
+}
+
+int ffw_frame_get_color_primaries(const AVFrame* frame) {
+    return frame->color_primaries;
+}
+
+int ffw_frame_get_color_transfer(const AVFrame* frame) {
+    return frame->color_trc;
+}
+
+int ffw_frame_get_color_space(const AVFrame* frame) {
+    return frame->colorspace;
+}
+
+void ffw_frame_set_color_range(AVFrame* frame, int range) {
+    frame->color_range = range;
+}
+
+void ffw_frame_set_color_primaries(AVFrame* frame, int primaries) {
+    frame->color_primaries = primaries;
+}
+
+void ffw_frame_set_color_transfer(AVFrame* frame, int transfer) {
+    frame->color_trc = transfer;
+}
+
+void ffw_frame_set_color_space(AVFrame* frame, int space) {
+    frame->colorspace = space;
+}
+
+int ffw_frame_is_hardware(const AVFrame* frame) {
+    return frame->hw_frames_ctx != NULL;
+}
+
+int ffw_frame_is_corrupt(const AVFrame* frame) {
+    return (frame->flags & AV_FRAME_FLAG_CORRUPT) != 0;
+}
+
+size_t ffw_frame_get_region_of_interest_count(const AVFrame* frame) {
+    const AVFrameSideData* sd;
+    const AVRegionOfInterest* roi;
+
+    sd = av_frame_get_side_data(frame, AV_FRAME_DATA_REGIONS_OF_INTEREST);
+
+    if (!sd || sd->size < sizeof(uint32_t)) {
+        return 0;
+    }
+
+    roi = (const AVRegionOfInterest*)sd->data;
+
+    // ignore malformed side data (the same check is done by libx264 and
+    // libvpx)
+    if (roi->self_size < sizeof(AVRegionOfInterest) || sd->size % roi->self_size) {
+        return 0;
+    }
+
+    return sd->size / roi->self_size;
+}
+
+void ffw_frame_get_region_of_interest(const AVFrame* frame, size_t index, int* left, int* top, int* right, int* bottom, double* qoffset) {
+    const AVFrameSideData* sd;
+    const AVRegionOfInterest* roi;
+
+    sd = av_frame_get_side_data(frame, AV_FRAME_DATA_REGIONS_OF_INTEREST);
+
+    roi = (const AVRegionOfInterest*)sd->data;
+    roi = (const AVRegionOfInterest*)(sd->data + index * roi->self_size);
+
+    *left = roi->left;
+    *top = roi->top;
+    *right = roi->right;
+    *bottom = roi->bottom;
+    *qoffset = av_q2d(roi->qoffset);
+}
+
+int ffw_frame_add_region_of_interest(AVFrame* frame, int left, int top, int right, int bottom, double qoffset) {
+    const AVFrameSideData* sd;
+    AVRegionOfInterest* roi;
+    AVBufferRef* buffer;
+    size_t count;
+
+    count = ffw_frame_get_region_of_interest_count(frame);
+    sd = av_frame_get_side_data(frame, AV_FRAME_DATA_REGIONS_OF_INTEREST);
+
+    // we cannot append to an array of structures of a different size
+    if (count > 0 && ((const AVRegionOfInterest*)sd->data)->self_size != sizeof(AVRegionOfInterest)) {
+        return AVERROR(EINVAL);
+    }
+
+    buffer = av_buffer_alloc((count + 1) * sizeof(AVRegionOfInterest));
+
+    if (!buffer) {
+        return AVERROR(ENOMEM);
+    }
+
+    if (count > 0) {
+        memcpy(buffer->data, sd->data, count * sizeof(AVRegionOfInterest));
+    }
+
+    roi = (AVRegionOfInterest*)buffer->data + count;
+
+    roi->self_size = sizeof(AVRegionOfInterest);
+    roi->left = left;
+    roi->top = top;
+    roi->right = right;
+    roi->bottom = bottom;
+    roi->qoffset = av_d2q(qoffset, 1000);
+
+    av_frame_remove_side_data(frame, AV_FRAME_DATA_REGIONS_OF_INTEREST);
+
+    if (!av_frame_new_side_data_from_buf(frame, AV_FRAME_DATA_REGIONS_OF_INTEREST, buffer)) {
+        av_buffer_unref(&buffer);
+        return AVERROR(ENOMEM);
+    }
+
+    return 0;
+}
+
+const uint8_t* ffw_frame_get_a53_cc(const AVFrame* frame, size_t* size) {
+    const AVFrameSideData* sd;
+
+    sd = av_frame_get_side_data(frame, AV_FRAME_DATA_A53_CC);
+
+    if (!sd) {
+        return NULL;
+    }
+
+    *size = sd->size;
+
+    return sd->data;
+}
+
+int ffw_frame_set_a53_cc(AVFrame* frame, const uint8_t* data, size_t size) {
+    AVFrameSideData* sd;
+
+    av_frame_remove_side_data(frame, AV_FRAME_DATA_A53_CC);
+
+    // empty data means no captions
+    if (!size) {
+        return 0;
+    }
+
+    sd = av_frame_new_side_data(frame, AV_FRAME_DATA_A53_CC, size);
+
+    if (!sd) {
+        return AVERROR(ENOMEM);
+    }
+
+    memcpy(sd->data, data, size);
+
+    return 0;
+}
+
+int ffw_frame_set_qp_table(AVFrame* frame, const int8_t* qp, size_t stride) {
+#ifdef FFW_FEATURE_VIDEO_ENC_PARAMS
+    AVVideoEncParams* params;
+    AVVideoBlockParams* block;
+    unsigned int mb_width;
+    unsigned int mb_height;
+    unsigned int x;
+    unsigned int y;
+
+    // the table contains one value for every 16x16 macroblock
+    mb_width = (frame->width + 15) >> 4;
+    mb_height = (frame->height + 15) >> 4;
+
+    av_frame_remove_side_data(frame, AV_FRAME_DATA_VIDEO_ENC_PARAMS);
+
+    params = av_video_enc_params_create_side_data(frame, AV_VIDEO_ENC_PARAMS_MPEG2, mb_width * mb_height);
+
+    if (!params) {
+        return AVERROR(ENOMEM);
+    }
+
+    params->qp = 0;
+
+    for (y = 0; y < mb_height; y++) {
+        for (x = 0; x < mb_width; x++) {
+            block = av_video_enc_params_block(params, y * mb_width + x);
+
+            block->src_x = x << 4;
+            block->src_y = y << 4;
+            block->w = 16;
+            block->h = 16;
+            block->delta_qp = qp[y * stride + x];
+        }
+    }
+
+    return 0;
+#else
+    return AVERROR(ENOSYS);
+#endif
+}
+
+uint64_t ffw_frame_get_opaque(const AVFrame* frame) {
+    uint64_t res = 0;
+
+    if (frame->opaque_ref && frame->opaque_ref->size >= sizeof(uint64_t)) {
+        memcpy(&res, frame->opaque_ref->data, sizeof(uint64_t));
+    }
+
+    return res;
+}
+
+int ffw_frame_set_opaque(AVFrame* frame, uint64_t opaque) {
+    AVBufferRef* buffer;
+
+    buffer = av_buffer_alloc(sizeof(uint64_t));
+
+    if (!buffer) {
+        return AVERROR(ENOMEM);
+    }
+
+    memcpy(buffer->data, &opaque, sizeof(uint64_t));
+
+    av_buffer_unref(&frame->opaque_ref);
+
+    frame->opaque_ref = buffer;
+
+    return 0;
+}
+
+int ffw_frame_is_interlaced(const AVFrame* frame) {
+#ifdef FFW_FEATURE_FRAME_INTERLACE_FLAGS
+    return (frame->flags & AV_FRAME_FLAG_INTERLACED) != 0;
+#else
+    return frame->interlaced_frame;
+#endif
+}
+
+int ffw_frame_is_top_field_first(const AVFrame* frame) {
+#ifdef FFW_FEATURE_FRAME_INTERLACE_FLAGS
+    return (frame->flags & AV_FRAME_FLAG_TOP_FIELD_FIRST) != 0;
+#else
+    return frame->top_field_first;
+#endif
+}
+
+void ffw_frame_set_interlaced(AVFrame* frame, int interlaced, int top_field_first) {
+#ifdef FFW_FEATURE_FRAME_INTERLACE_FLAGS
+    frame->flags &= ~(AV_FRAME_FLAG_INTERLACED | AV_FRAME_FLAG_TOP_FIELD_FIRST);
+
+    if (interlaced) {
+        frame->flags |= AV_FRAME_FLAG_INTERLACED;
+    }
+
+    if (top_field_first) {
+        frame->flags |= AV_FRAME_FLAG_TOP_FIELD_FIRST;
+    }
+#else
+    frame->interlaced_frame = interlaced;
+    frame->top_field_first = top_field_first;
+#endif
+}
+
+int ffw_frame_get_repeat_pict(const AVFrame* frame) {
+    return frame->repeat_pict;
+}
+
 int ffw_frame_get_picture_type(const AVFrame* frame) {
     switch (frame->pict_type) {
         case AV_PICTURE_TYPE_I: return 1;
@@ -0,0 +1,66 @@
+// Helpers used only by the Rust tests. The functions are compiled into a
+// separate static library that is linked only into the test binaries.
+
+#include <libavutil/buffer.h>
+#include <libavutil/frame.h>
+
+#ifdef FFW_FEATURE_VIDEO_ENC_PARAMS
+#include <libavutil/video_enc_params.h>
+#endif
+
+int ffw_frame_set_dummy_hw_frames_ctx(AVFrame* frame) {
+    AVBufferRef* buffer;
+
+    // NOTE: the buffer does not contain a valid hardware frames context
+    buffer = av_buffer_alloc(1);
+
+    if (!buffer) {
+        return AVERROR(ENOMEM);
+    }
+
+    av_buffer_unref(&frame->hw_frames_ctx);
+
+    frame->hw_frames_ctx = buffer;
+
+    return 0;
+}
+
+void ffw_frame_set_corrupt(AVFrame* frame) {
+    frame->flags |= AV_FRAME_FLAG_CORRUPT;
+}
+
+void ffw_frame_set_repeat_pict(AVFrame* frame, int repeat_pict) {
+    frame->repeat_pict = repeat_pict;
+}
+
+#ifdef FFW_FEATURE_VIDEO_ENC_PARAMS
+
+int ffw_frame_get_video_enc_params_block_count(const AVFrame* frame) {
+    AVFrameSideData* sd;
+    AVVideoEncParams* params;
+
+    sd = av_frame_get_side_data(frame, AV_FRAME_DATA_VIDEO_ENC_PARAMS);
+
+    if (!sd) {
+        return 0;
+    }
+
+    params = (AVVideoEncParams*)sd->data;
+
+    return params->nb_blocks;
+}
+
+void ffw_frame_get_video_enc_params_block(const AVFrame* frame, int index, int* src_x, int* src_y, int* delta_qp) {
+    AVFrameSideData* sd;
+    AVVideoBlockParams* block;
+
+    sd = av_frame_get_side_data(frame, AV_FRAME_DATA_VIDEO_ENC_PARAMS);
+
+    block = av_video_enc_params_block((AVVideoEncParams*)sd->data, index);
+
+    *src_x = block->src_x;
+    *src_y = block->src_y;
+    *delta_qp = block->delta_qp;
+}
+
+#endif // FFW_FEATURE_VIDEO_ENC_PARAMS
@@ -116,6 +116,11 @@ int ffw_codec_parameters_get_height(const AVCodecParameters* params) {
     return params->height;
 }
 
+void ffw_codec_parameters_get_sample_aspect_ratio(const AVCodecParameters* params, int* num, int* den) {
+    *num = params->sample_aspect_ratio.num;
+    *den = params->sample_aspect_ratio.den;
+}
+
 int ffw_codec_parameters_get_sample_rate(const AVCodecParameters* params) {
     return params->sample_rate;
 }
@@ -158,6 +163,11 @@ void ffw_codec_parameters_set_height(AVCodecParameters* params, int height) {
     params->height = height;
 }
 
+void ffw_codec_parameters_set_sample_aspect_ratio(AVCodecParameters* params, int num, int den) {
+    params->sample_aspect_ratio.num = num;
+    params->sample_aspect_ratio.den = den;
+}
+
 void ffw_codec_parameters_set_sample_rate(AVCodecParameters* params, int sample_rate) {
     params->sample_rate = sample_rate;
 }
@@ -34,6 +34,11 @@ extern "C" {
     fn ffw_codec_parameters_get_format(params: *const c_void) -> c_int;
     fn ffw_codec_parameters_get_width(params: *const c_void) -> c_int;
     fn ffw_codec_parameters_get_height(params: *const c_void) -> c_int;
+    fn ffw_codec_parameters_get_sample_aspect_ratio(
+        params: *const c_void,
+        num: *mut c_int,
+        den: *mut c_int,
+    );
     fn ffw_codec_parameters_get_sample_rate(params: *const c_void) -> c_int;
     fn ffw_codec_parameters_get_channel_layout(params: *const c_void) -> *const c_void;
     fn ffw_codec_parameters_get_codec_tag(params: *const c_void) -> u32;
@@ -43,6 +48,7 @@ extern "C" {
     fn ffw_codec_parameters_set_format(params: *mut c_void, format: c_int);
     fn ffw_codec_parameters_set_width(params: *mut c_void, width: c_int);
     fn ffw_codec_parameters_set_height(params: *mut c_void, height: c_int);
+    fn ffw_codec_parameters_set_sample_aspect_ratio(params: *mut c_void, num: c_int, den: c_int);
     fn ffw_codec_parameters_set_sample_rate(params: *mut c_void, rate: c_int);
     fn ffw_codec_parameters_set_channel_layout(params: *mut c_void, layout: *const c_void)
         -> c_int;
@@ -688,6 +694,15 @@ impl VideoCodecParametersBuilder {
         self
     }
 
+    /// Set sample aspect ratio. Use 0/1 if the aspect ratio is unknown.
+    pub fn sample_aspect_ratio(self, num: i32, den: i32) -> Self {
+        unsafe {
+            ffw_codec_parameters_set_sample_aspect_ratio(self.inner.ptr, num as _, den as _);
+        }
+
+        self
+    }
+
     /// Set codec tag.
     pub fn codec_tag(self, codec_tag: impl Into<CodecTag>) -> Self {
         unsafe {
@@ -750,6 +765,31 @@ impl VideoCodecParameters {
         VideoCodecParametersBuilder::new(codec)
     }
 
+    /// Create codec parameters describing raw (uncompressed) video with a
+    /// given pixel format, resolution and sample aspect ratio.
+    ///
+    /// This is useful when the video does not come from a decoder (e.g. it
+    /// comes from a capture device) but something still needs to be
+    /// configured using codec parameters. Use 0/1 as the sample aspect ratio
+    /// if it is unknown.
+    pub fn for_raw_video(
+        pixel_format: PixelFormat,
+        width: usize,
+        height: usize,
+        sample_aspect_ratio: (i32, i32),
+    ) -> Result<Self, Error> {
+        let (sar_num, sar_den) = sample_aspect_ratio;
+
+        let res = Self::builder("rawvideo")?
+            .pixel_format(pixel_format)
+            .width(width)
+            .height(height)
+            .sample_aspect_ratio(sar_num, sar_den)
+            .build();
+
+        Ok(res)
+    }
+
     /// Get raw pointer to the underlying object.
     pub(crate) fn as_ptr(&self) -> *const c_void {
         self.inner.ptr
@@ -787,6 +827,19 @@ impl VideoCodecParameters {
         unsafe { ffw_codec_parameters_get_height(self.inner.ptr) as _ }
     }
 
+    /// Get sample aspect ratio as a (num, den) pair. The aspect ratio is
+    /// unknown if the numerator is zero.
+    pub fn sample_aspect_ratio(&self) -> (i32, i32) {
+        let mut num = 0;
+        let mut den = 0;
+
+        unsafe {
+            ffw_codec_parameters_get_sample_aspect_ratio(self.inner.ptr, &mut num, &mut den);
+        }
+
+        (num, den)
+    }
+
     /// Get codec tag.
     pub fn codec_tag(&self) -> CodecTag {
         self.inner.codec_tag()
@@ -991,3 +1044,27 @@ pub trait Encoder {
     /// Take the next packet from the encoder.
     fn take(&mut self) -> Result<Option<Packet>, Error>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::VideoCodecParameters;
+
+    use crate::codec::video::frame::get_pixel_format;
+
+    #[test]
+    fn test_raw_video_parameters() {
+        let pixel_format = get_pixel_format("yuv420p");
+
+        let params = VideoCodecParameters::for_raw_video(pixel_format, 720, 576, (16, 15)).unwrap();
+
+        assert!(params.pixel_format() == pixel_format);
+        assert_eq!(params.width(), 720);
+        assert_eq!(params.height(), 576);
+        assert_eq!(params.sample_aspect_ratio(), (16, 15));
+        assert_eq!(params.decoder_name(), Some("rawvideo"));
+
+        let params = VideoCodecParameters::for_raw_video(pixel_format, 640, 480, (0, 1)).unwrap();
+
+        assert_eq!(params.sample_aspect_ratio(), (0, 1));
+    }
+}
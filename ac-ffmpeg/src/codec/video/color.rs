@@ -0,0 +1,321 @@
+//! Colors.
+//!
+//! The `Display` implementations of `Rgb` and `Rgba` use the syntax expected
+//! by FFmpeg filter options.
+
+use std::{
+    ffi::{CStr, CString},
+    fmt::{self, Display, Formatter},
+    os::raw::{c_char, c_int},
+    str::FromStr,
+};
+
+extern "C" {
+    fn ffw_get_color_range_name(range: c_int) -> *const c_char;
+    fn ffw_get_color_primaries_name(primaries: c_int) -> *const c_char;
+    fn ffw_get_color_transfer_name(transfer: c_int) -> *const c_char;
+    fn ffw_get_color_space_name(space: c_int) -> *const c_char;
+    fn ffw_get_color_range_by_name(name: *const c_char) -> c_int;
+    fn ffw_get_color_primaries_by_name(name: *const c_char) -> c_int;
+    fn ffw_get_color_transfer_by_name(name: *const c_char) -> c_int;
+    fn ffw_get_color_space_by_name(name: *const c_char) -> c_int;
+}
+
+/// An RGB color.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+
+    /// Create a new color.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Add alpha channel.
+    pub const fn with_alpha(self, a: u8) -> Rgba {
+        Rgba::new(self.r, self.g, self.b, a)
+    }
+}
+
+impl Display for Rgb {
+    /// Format the color as `0xRRGGBB`.
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "0x{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+}
+
+/// An RGB color with alpha channel.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Rgba {
+    /// Create a new color.
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl From<Rgb> for Rgba {
+    fn from(color: Rgb) -> Self {
+        color.with_alpha(255)
+    }
+}
+
+impl Display for Rgba {
+    /// Format the color as `0xRRGGBBAA`.
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "0x{:02X}{:02X}{:02X}{:02X}",
+            self.r, self.g, self.b, self.a
+        )
+    }
+}
+
+/// Get a color property name from a given C string (if any).
+fn property_name(ptr: *const c_char) -> Option<&'static str> {
+    if ptr.is_null() {
+        return None;
+    }
+
+    let name = unsafe { CStr::from_ptr(ptr as _) };
+
+    name.to_str().ok()
+}
+
+/// Find a color property by its name using a given lookup function.
+fn property_by_name(
+    name: &str,
+    lookup: unsafe extern "C" fn(*const c_char) -> c_int,
+) -> Result<c_int, UnknownColorProperty> {
+    let name = CString::new(name).expect("invalid color property name");
+
+    let res = unsafe { lookup(name.as_ptr() as _) };
+
+    if res < 0 {
+        Err(UnknownColorProperty)
+    } else {
+        Ok(res)
+    }
+}
+
+/// An error indicating an unknown color property name.
+#[derive(Debug, Copy, Clone)]
+pub struct UnknownColorProperty;
+
+impl Display for UnknownColorProperty {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str("unknown color property")
+    }
+}
+
+impl std::error::Error for UnknownColorProperty {}
+
+/// Color range (i.e. limited/MPEG or full/JPEG).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ColorRange(c_int);
+
+impl ColorRange {
+    pub const UNSPECIFIED: ColorRange = ColorRange(0);
+    /// Limited range (e.g. 16-235 for 8-bit luma).
+    pub const MPEG: ColorRange = ColorRange(1);
+    /// Full range (e.g. 0-255 for 8-bit luma).
+    pub const JPEG: ColorRange = ColorRange(2);
+
+    /// Create a color range value from a given raw representation.
+    pub(crate) fn from_raw(v: c_int) -> Self {
+        Self(v)
+    }
+
+    /// Get the raw value.
+    pub(crate) fn into_raw(self) -> c_int {
+        self.0
+    }
+
+    /// Get name of the color range. The method returns `None` for reserved
+    /// or unknown values.
+    pub fn name(self) -> Option<&'static str> {
+        property_name(unsafe { ffw_get_color_range_name(self.0) })
+    }
+}
+
+impl FromStr for ColorRange {
+    type Err = UnknownColorProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        property_by_name(s, ffw_get_color_range_by_name).map(Self)
+    }
+}
+
+/// Color primaries.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ColorPrimaries(c_int);
+
+impl ColorPrimaries {
+    pub const BT709: ColorPrimaries = ColorPrimaries(1);
+    pub const UNSPECIFIED: ColorPrimaries = ColorPrimaries(2);
+    pub const BT470BG: ColorPrimaries = ColorPrimaries(5);
+    pub const SMPTE170M: ColorPrimaries = ColorPrimaries(6);
+    pub const BT2020: ColorPrimaries = ColorPrimaries(9);
+
+    /// Create a color primaries value from a given raw representation.
+    pub(crate) fn from_raw(v: c_int) -> Self {
+        Self(v)
+    }
+
+    /// Get the raw value.
+    pub(crate) fn into_raw(self) -> c_int {
+        self.0
+    }
+
+    /// Get name of the color primaries. The method returns `None` for
+    /// reserved or unknown values.
+    pub fn name(self) -> Option<&'static str> {
+        property_name(unsafe { ffw_get_color_primaries_name(self.0) })
+    }
+}
+
+impl FromStr for ColorPrimaries {
+    type Err = UnknownColorProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        property_by_name(s, ffw_get_color_primaries_by_name).map(Self)
+    }
+}
+
+/// Color transfer characteristic.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ColorTransferCharacteristic(c_int);
+
+impl ColorTransferCharacteristic {
+    pub const BT709: ColorTransferCharacteristic = ColorTransferCharacteristic(1);
+    pub const UNSPECIFIED: ColorTransferCharacteristic = ColorTransferCharacteristic(2);
+    pub const SMPTE170M: ColorTransferCharacteristic = ColorTransferCharacteristic(6);
+    pub const IEC61966_2_1: ColorTransferCharacteristic = ColorTransferCharacteristic(13);
+    /// Perceptual quantizer (HDR10).
+    pub const SMPTE2084: ColorTransferCharacteristic = ColorTransferCharacteristic(16);
+    /// Hybrid log-gamma.
+    pub const ARIB_STD_B67: ColorTransferCharacteristic = ColorTransferCharacteristic(18);
+
+    /// Create a color transfer characteristic value from a given raw
+    /// representation.
+    pub(crate) fn from_raw(v: c_int) -> Self {
+        Self(v)
+    }
+
+    /// Get the raw value.
+    pub(crate) fn into_raw(self) -> c_int {
+        self.0
+    }
+
+    /// Get name of the color transfer characteristic. The method returns
+    /// `None` for reserved or unknown values.
+    pub fn name(self) -> Option<&'static str> {
+        property_name(unsafe { ffw_get_color_transfer_name(self.0) })
+    }
+}
+
+impl FromStr for ColorTransferCharacteristic {
+    type Err = UnknownColorProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        property_by_name(s, ffw_get_color_transfer_by_name).map(Self)
+    }
+}
+
+/// Color space (i.e. the YUV matrix coefficients).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ColorSpace(c_int);
+
+impl ColorSpace {
+    pub const RGB: ColorSpace = ColorSpace(0);
+    pub const BT709: ColorSpace = ColorSpace(1);
+    pub const UNSPECIFIED: ColorSpace = ColorSpace(2);
+    pub const BT470BG: ColorSpace = ColorSpace(5);
+    pub const SMPTE170M: ColorSpace = ColorSpace(6);
+    /// BT.2020 non-constant luminance.
+    pub const BT2020_NCL: ColorSpace = ColorSpace(9);
+
+    /// Create a color space value from a given raw representation.
+    pub(crate) fn from_raw(v: c_int) -> Self {
+        Self(v)
+    }
+
+    /// Get the raw value.
+    pub(crate) fn into_raw(self) -> c_int {
+        self.0
+    }
+
+    /// Get name of the color space. The method returns `None` for reserved
+    /// or unknown values.
+    pub fn name(self) -> Option<&'static str> {
+        property_name(unsafe { ffw_get_color_space_name(self.0) })
+    }
+}
+
+impl FromStr for ColorSpace {
+    type Err = UnknownColorProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        property_by_name(s, ffw_get_color_space_by_name).map(Self)
+    }
+}
+
+/// Color properties of a picture.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ColorProperties {
+    pub range: ColorRange,
+    pub primaries: ColorPrimaries,
+    pub transfer: ColorTransferCharacteristic,
+    pub space: ColorSpace,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ColorPrimaries, ColorRange, ColorSpace, ColorTransferCharacteristic, Rgb, Rgba};
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Rgb::new(255, 128, 0).to_string(), "0xFF8000");
+        assert_eq!(Rgba::new(0, 16, 32, 128).to_string(), "0x00102080");
+        assert_eq!(Rgba::from(Rgb::WHITE).to_string(), "0xFFFFFFFF");
+    }
+
+    #[test]
+    fn test_property_names() {
+        assert_eq!(ColorRange::JPEG.name(), Some("pc"));
+        assert_eq!(ColorPrimaries::BT2020.name(), Some("bt2020"));
+
+        // reserved values do not have any name
+        assert_eq!(ColorPrimaries::from_raw(15).name(), None);
+    }
+
+    #[test]
+    fn test_property_from_str() {
+        assert_eq!("tv".parse::<ColorRange>().unwrap(), ColorRange::MPEG);
+        assert_eq!("bt709".parse::<ColorSpace>().unwrap(), ColorSpace::BT709);
+
+        // values without a named constant
+        let primaries = "smpte432".parse::<ColorPrimaries>().unwrap();
+        let transfer = "linear".parse::<ColorTransferCharacteristic>().unwrap();
+        let space = "bt2020c".parse::<ColorSpace>().unwrap();
+
+        assert_eq!(primaries.name(), Some("smpte432"));
+        assert_eq!(transfer.name(), Some("linear"));
+        assert_eq!(space.name(), Some("bt2020c"));
+
+        assert!("foo".parse::<ColorPrimaries>().is_err());
+    }
+}
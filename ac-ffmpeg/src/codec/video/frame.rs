@@ -1,6 +1,7 @@
 //! Video frame.
 
 use std::{
+    convert::TryFrom,
     ffi::{CStr, CString},
     fmt::{self, Display, Formatter},
     marker::PhantomData,
@@ -9,9 +10,19 @@ use std::{
     ptr,
     slice::{self, Chunks, ChunksMut},
     str::FromStr,
+    time::Duration,
 };
 
-use crate::time::{TimeBase, Timestamp};
+use crate::{
+    codec::video::{
+        color::{
+            ColorPrimaries, ColorProperties, ColorRange, ColorSpace, ColorTransferCharacteristic,
+        },
+        geometry::Rect,
+    },
+    time::{rescale_duration, TimeBase, Timestamp},
+    Error,
+};
 
 extern "C" {
     fn ffw_get_pixel_format_by_name(name: *const c_char) -> c_int;
@@ -25,6 +36,8 @@ extern "C" {
     fn ffw_frame_get_best_effort_timestamp(frame: *const c_void) -> i64;
     fn ffw_frame_get_pts(frame: *const c_void) -> i64;
     fn ffw_frame_set_pts(frame: *mut c_void, pts: i64);
+    fn ffw_frame_get_duration(frame: *const c_void) -> i64;
+    fn ffw_frame_set_duration(frame: *mut c_void, duration: i64);
     fn ffw_frame_get_plane_data(frame: *mut c_void, index: usize) -> *mut u8;
     fn ffw_frame_get_line_size(frame: *const c_void, plane: usize) -> usize;
     fn ffw_frame_get_line_count(frame: *const c_void, plane: usize) -> usize;
@@ -32,8 +45,45 @@ extern "C" {
     fn ffw_frame_free(frame: *mut c_void);
     fn ffw_frame_is_writable(frame: *const c_void) -> c_int;
     fn ffw_frame_make_writable(frame: *mut c_void) -> c_int;
+    fn ffw_frame_get_repeat_pict(frame: *const c_void) -> c_int;
     fn ffw_frame_get_picture_type(frame: *const c_void) -> c_int;
     fn ffw_frame_set_picture_type(frame: *mut c_void, picture_type: c_int);
+    fn ffw_frame_get_color_range(frame: *const c_void) -> c_int;
+    fn ffw_frame_get_color_primaries(frame: *const c_void) -> c_int;
+    fn ffw_frame_get_color_transfer(frame: *const c_void) -> c_int;
+    fn ffw_frame_get_color_space(frame: *const c_void) -> c_int;
+    fn ffw_frame_set_color_range(frame: *mut c_void, range: c_int);
+    fn ffw_frame_set_color_primaries(frame: *mut c_void, primaries: c_int);
+    fn ffw_frame_set_color_transfer(frame: *mut c_void, transfer: c_int);
+    fn ffw_frame_set_color_space(frame: *mut c_void, space: c_int);
+    fn ffw_frame_is_hardware(frame: *const c_void) -> c_int;
+    fn ffw_frame_get_region_of_interest_count(frame: *const c_void) -> usize;
+    fn ffw_frame_get_region_of_interest(
+        frame: *const c_void,
+        index: usize,
+        left: *mut c_int,
+        top: *mut c_int,
+        right: *mut c_int,
+        bottom: *mut c_int,
+        qoffset: *mut f64,
+    );
+    fn ffw_frame_add_region_of_interest(
+        frame: *mut c_void,
+        left: c_int,
+        top: c_int,
+        right: c_int,
+        bottom: c_int,
+        qoffset: f64,
+    ) -> c_int;
+    fn ffw_frame_is_corrupt(frame: *const c_void) -> c_int;
+    fn ffw_frame_get_a53_cc(frame: *const c_void, size: *mut usize) -> *const u8;
+    fn ffw_frame_set_a53_cc(frame: *mut c_void, data: *const u8, size: usize) -> c_int;
+    fn ffw_frame_set_qp_table(frame: *mut c_void, qp: *const i8, stride: usize) -> c_int;
+    fn ffw_frame_get_opaque(frame: *const c_void) -> u64;
+    fn ffw_frame_set_opaque(frame: *mut c_void, opaque: u64) -> c_int;
+    fn ffw_frame_is_interlaced(frame: *const c_void) -> c_int;
+    fn ffw_frame_is_top_field_first(frame: *const c_void) -> c_int;
+    fn ffw_frame_set_interlaced(frame: *mut c_void, interlaced: c_int, top_field_first: c_int);
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -392,9 +442,11 @@ impl VideoFrameMut {
     /// given time base.)
     pub fn with_time_base(mut self, time_base: TimeBase) -> Self {
         let new_pts = self.pts().with_time_base(time_base);
+        let new_duration = rescale_duration(self.raw_duration(), self.time_base, time_base);
 
         unsafe {
             ffw_frame_set_pts(self.ptr, new_pts.timestamp());
+            ffw_frame_set_duration(self.ptr, new_duration);
         }
 
         self.time_base = time_base;
@@ -418,6 +470,43 @@ impl VideoFrameMut {
         self
     }
 
+    /// Get frame duration.
+    ///
+    /// The method returns `None` if the duration is lower or equal to zero.
+    pub fn duration(&self) -> Option<Duration> {
+        let duration = self.raw_duration();
+
+        if duration > 0 {
+            let z = Timestamp::new(0, self.time_base);
+            let d = Timestamp::new(duration, self.time_base);
+
+            Some(d - z)
+        } else {
+            None
+        }
+    }
+
+    /// Set frame duration.
+    pub fn with_duration(self, duration: Duration) -> Self {
+        let d = Timestamp::new(0, self.time_base) + duration;
+
+        unsafe { ffw_frame_set_duration(self.ptr, d.timestamp()) }
+
+        self
+    }
+
+    /// Get frame duration in time base units.
+    pub fn raw_duration(&self) -> i64 {
+        unsafe { ffw_frame_get_duration(self.ptr) }
+    }
+
+    /// Set frame duration in time base units.
+    pub fn with_raw_duration(self, duration: i64) -> Self {
+        unsafe { ffw_frame_set_duration(self.ptr, duration) }
+
+        self
+    }
+
     /// Get picture type
     pub fn picture_type(&self) -> PictureType {
         unsafe { PictureType::from_raw(ffw_frame_get_picture_type(self.ptr)) }
@@ -429,6 +518,44 @@ impl VideoFrameMut {
         self
     }
 
+    /// Get color properties of the picture.
+    pub fn color_properties(&self) -> ColorProperties {
+        let range = unsafe { ffw_frame_get_color_range(self.ptr) };
+        let primaries = unsafe { ffw_frame_get_color_primaries(self.ptr) };
+        let transfer = unsafe { ffw_frame_get_color_transfer(self.ptr) };
+        let space = unsafe { ffw_frame_get_color_space(self.ptr) };
+
+        ColorProperties {
+            range: ColorRange::from_raw(range),
+            primaries: ColorPrimaries::from_raw(primaries),
+            transfer: ColorTransferCharacteristic::from_raw(transfer),
+            space: ColorSpace::from_raw(space),
+        }
+    }
+
+    /// Set color properties of the picture.
+    pub fn with_color_properties(self, properties: ColorProperties) -> Self {
+        unsafe {
+            ffw_frame_set_color_range(self.ptr, properties.range.into_raw());
+            ffw_frame_set_color_primaries(self.ptr, properties.primaries.into_raw());
+            ffw_frame_set_color_transfer(self.ptr, properties.transfer.into_raw());
+            ffw_frame_set_color_space(self.ptr, properties.space.into_raw());
+        }
+
+        self
+    }
+
+    /// Check if the picture is interlaced.
+    pub fn is_interlaced(&self) -> bool {
+        unsafe { ffw_frame_is_interlaced(self.ptr) != 0 }
+    }
+
+    /// Check if the top field is displayed first. The value is meaningful
+    /// only for interlaced pictures.
+    pub fn top_field_first(&self) -> bool {
+        unsafe { ffw_frame_is_top_field_first(self.ptr) != 0 }
+    }
+
     /// Get picture planes.
     pub fn planes(&self) -> Planes {
         Planes::from(self)
@@ -493,6 +620,45 @@ impl VideoFrame {
         Planes::from(self)
     }
 
+    /// Get color properties of the picture.
+    pub fn color_properties(&self) -> ColorProperties {
+        let range = unsafe { ffw_frame_get_color_range(self.ptr) };
+        let primaries = unsafe { ffw_frame_get_color_primaries(self.ptr) };
+        let transfer = unsafe { ffw_frame_get_color_transfer(self.ptr) };
+        let space = unsafe { ffw_frame_get_color_space(self.ptr) };
+
+        ColorProperties {
+            range: ColorRange::from_raw(range),
+            primaries: ColorPrimaries::from_raw(primaries),
+            transfer: ColorTransferCharacteristic::from_raw(transfer),
+            space: ColorSpace::from_raw(space),
+        }
+    }
+
+    /// Set color properties of the picture.
+    pub fn with_color_properties(self, properties: ColorProperties) -> Self {
+        unsafe {
+            ffw_frame_set_color_range(self.ptr, properties.range.into_raw());
+            ffw_frame_set_color_primaries(self.ptr, properties.primaries.into_raw());
+            ffw_frame_set_color_transfer(self.ptr, properties.transfer.into_raw());
+            ffw_frame_set_color_space(self.ptr, properties.space.into_raw());
+        }
+
+        self
+    }
+
+    /// Check if the frame data is stored in hardware (e.g. GPU) memory. Note
+    /// that planes of hardware frames cannot be accessed directly.
+    pub fn is_hardware(&self) -> bool {
+        unsafe { ffw_frame_is_hardware(self.ptr) != 0 }
+    }
+
+    /// Check if the frame has been marked as corrupt (e.g. by a decoder that
+    /// was not able to decode the picture completely).
+    pub fn is_corrupt(&self) -> bool {
+        unsafe { ffw_frame_is_corrupt(self.ptr) != 0 }
+    }
+
     /// Get frame time base.
     pub fn time_base(&self) -> TimeBase {
         self.time_base
@@ -502,9 +668,11 @@ impl VideoFrame {
     /// given time base.)
     pub fn with_time_base(mut self, time_base: TimeBase) -> Self {
         let new_pts = self.pts().with_time_base(time_base);
+        let new_duration = rescale_duration(self.raw_duration(), self.time_base, time_base);
 
         unsafe {
             ffw_frame_set_pts(self.ptr, new_pts.timestamp());
+            ffw_frame_set_duration(self.ptr, new_duration);
         }
 
         self.time_base = time_base;
@@ -535,11 +703,318 @@ impl VideoFrame {
         self
     }
 
+    /// Get frame duration.
+    ///
+    /// The method returns `None` if the duration is lower or equal to zero.
+    pub fn duration(&self) -> Option<Duration> {
+        let duration = self.raw_duration();
+
+        if duration > 0 {
+            let z = Timestamp::new(0, self.time_base);
+            let d = Timestamp::new(duration, self.time_base);
+
+            Some(d - z)
+        } else {
+            None
+        }
+    }
+
+    /// Set frame duration.
+    pub fn with_duration(self, duration: Duration) -> Self {
+        let d = Timestamp::new(0, self.time_base) + duration;
+
+        unsafe { ffw_frame_set_duration(self.ptr, d.timestamp()) }
+
+        self
+    }
+
+    /// Get frame duration in time base units.
+    pub fn raw_duration(&self) -> i64 {
+        unsafe { ffw_frame_get_duration(self.ptr) }
+    }
+
+    /// Set frame duration in time base units.
+    pub fn with_raw_duration(self, duration: i64) -> Self {
+        unsafe { ffw_frame_set_duration(self.ptr, duration) }
+
+        self
+    }
+
     /// Get picture type
     pub fn picture_type(&self) -> PictureType {
         unsafe { PictureType::from_raw(ffw_frame_get_picture_type(self.ptr)) }
     }
 
+    /// Check if the picture is interlaced.
+    pub fn is_interlaced(&self) -> bool {
+        unsafe { ffw_frame_is_interlaced(self.ptr) != 0 }
+    }
+
+    /// Check if the top field is displayed first. The value is meaningful
+    /// only for interlaced pictures.
+    pub fn top_field_first(&self) -> bool {
+        unsafe { ffw_frame_is_top_field_first(self.ptr) != 0 }
+    }
+
+    /// Mark the picture as interlaced or progressive and set its field order.
+    /// This is useful if the interlacing is signaled out-of-band (e.g. by a
+    /// capture device).
+    pub fn set_interlaced(&mut self, interlaced: bool, top_field_first: bool) {
+        unsafe { ffw_frame_set_interlaced(self.ptr, interlaced as _, top_field_first as _) }
+    }
+
+    /// Get the number of fields the picture should be delayed by when
+    /// displayed (i.e. the picture should be displayed for `1 + repeat_pict / 2`
+    /// frame durations). This is used by pulldown (telecined) content.
+    pub fn repeat_pict(&self) -> i32 {
+        unsafe { ffw_frame_get_repeat_pict(self.ptr) as _ }
+    }
+
+    /// Get regions of interest attached to the frame. Each region is
+    /// returned together with its quantisation offset. Regions can be
+    /// attached using `add_roi()`.
+    pub fn regions_of_interest(&self) -> Vec<(Rect, f32)> {
+        let count = unsafe { ffw_frame_get_region_of_interest_count(self.ptr) };
+
+        let mut res = Vec::with_capacity(count);
+
+        for index in 0..count {
+            let mut left = 0;
+            let mut top = 0;
+            let mut right = 0;
+            let mut bottom = 0;
+            let mut qoffset = 0.0;
+
+            unsafe {
+                ffw_frame_get_region_of_interest(
+                    self.ptr,
+                    index,
+                    &mut left,
+                    &mut top,
+                    &mut right,
+                    &mut bottom,
+                    &mut qoffset,
+                );
+            }
+
+            let x = left.max(0) as usize;
+            let y = top.max(0) as usize;
+            let w = (right.max(0) as usize).saturating_sub(x);
+            let h = (bottom.max(0) as usize).saturating_sub(y);
+
+            res.push((Rect::new(x, y, w, h), qoffset as f32));
+        }
+
+        res
+    }
+
+    /// Attach a region of interest to the frame. Encoders supporting the
+    /// region of interest side data will adjust quality of the region
+    /// according to the quantisation offset. The offset must be in the range
+    /// from -1 to 1 where negative values mean better quality.
+    ///
+    /// The region is appended to the regions returned by
+    /// `regions_of_interest()`. An error is returned if the region is empty
+    /// or if its edges cannot be represented by FFmpeg.
+    pub fn add_roi(&mut self, rect: Rect, qoffset: f32) -> Result<(), Error> {
+        if rect.is_empty() {
+            return Err(Error::new("empty region of interest"));
+        } else if !(-1.0..=1.0).contains(&qoffset) {
+            return Err(Error::new("quantisation offset out of range"));
+        }
+
+        let edge = |offset: usize, size: usize| {
+            offset
+                .checked_add(size)
+                .and_then(|end| c_int::try_from(end).ok())
+                .ok_or_else(|| Error::new("region of interest out of range"))
+        };
+
+        let right = edge(rect.x, rect.w)?;
+        let bottom = edge(rect.y, rect.h)?;
+
+        // the left and top edges cannot be greater than the right and bottom
+        // ones, so they fit as well
+        let ret = unsafe {
+            ffw_frame_add_region_of_interest(
+                self.ptr,
+                rect.x as _,
+                rect.y as _,
+                right,
+                bottom,
+                qoffset as _,
+            )
+        };
+
+        if ret < 0 {
+            return Err(Error::from_raw_error_code(ret));
+        }
+
+        Ok(())
+    }
+
+    /// Get closed caption (ATSC A53 Part 4, i.e. CEA-708 encapsulating
+    /// CEA-608) data attached to the frame.
+    pub fn caption_data(&self) -> Option<&[u8]> {
+        let mut size = 0;
+
+        unsafe {
+            let data = ffw_frame_get_a53_cc(self.ptr, &mut size);
+
+            if data.is_null() {
+                None
+            } else {
+                Some(slice::from_raw_parts(data, size))
+            }
+        }
+    }
+
+    /// Attach closed caption (ATSC A53 Part 4) data to the frame. Any
+    /// existing caption data will be replaced. Passing an empty slice removes
+    /// the caption data from the frame.
+    pub fn set_caption_data(&mut self, data: &[u8]) {
+        let ret = unsafe { ffw_frame_set_a53_cc(self.ptr, data.as_ptr(), data.len()) };
+
+        if ret < 0 {
+            panic!("unable to allocate caption data");
+        }
+    }
+
+    /// Attach a table of quantizers to the frame. The table must contain one
+    /// value for every 16x16 macroblock of the picture, `stride` is the
+    /// distance between two consecutive rows of the table. Any existing
+    /// quantizer data will be replaced.
+    ///
+    /// The table is stored as the video encoding parameters side data, so it
+    /// can be used by the post-processing filters or encoders. Note that any
+    /// processing changing the picture geometry makes the table invalid.
+    pub fn set_qp_table(&mut self, qp: &[i8], stride: usize) -> Result<(), Error> {
+        check_qp_table(self.width(), self.height(), qp.len(), stride)?;
+
+        let ret = unsafe { ffw_frame_set_qp_table(self.ptr, qp.as_ptr(), stride) };
+
+        if ret < 0 {
+            return Err(Error::from_raw_error_code(ret));
+        }
+
+        Ok(())
+    }
+
+    /// Get the user tag attached to the frame. Zero is returned if there is
+    /// no tag.
+    pub fn opaque(&self) -> u64 {
+        unsafe { ffw_frame_get_opaque(self.ptr) }
+    }
+
+    /// Attach a user tag to the frame. The tag is kept when the frame is
+    /// cloned and FFmpeg also copies it to frames derived from this one (i.e.
+    /// frames with copied properties). It is lost if a component creates a
+    /// completely new frame.
+    pub fn set_opaque(&mut self, tag: u64) {
+        let ret = unsafe { ffw_frame_set_opaque(self.ptr, tag) };
+
+        if ret < 0 {
+            panic!("unable to allocate frame tag");
+        }
+    }
+
+    /// Get RGB value of a pixel at a given position.
+    ///
+    /// Only the common packed RGB, planar YUV, semi-planar YUV and gray pixel
+    /// formats are supported. YUV values are converted using the BT.709
+    /// coefficients if the frame is tagged with the BT.709 color space and
+    /// using the BT.601 coefficients otherwise. Full range YUV is assumed for
+    /// the `yuvj*` pixel formats and for frames tagged with the JPEG color
+    /// range; limited range is assumed otherwise. An error is returned for unsupported pixel formats and
+    /// for positions outside of the picture.
+    pub fn sample_rgb(&self, x: u32, y: u32) -> Result<(u8, u8, u8), Error> {
+        let x = x as usize;
+        let y = y as usize;
+
+        if x >= self.width() || y >= self.height() {
+            return Err(Error::new("pixel position out of bounds"));
+        }
+
+        let name = self.pixel_format().name();
+
+        let color_properties = self.color_properties();
+
+        let full_range = name.starts_with("yuvj") || color_properties.range == ColorRange::JPEG;
+
+        let matrix = if color_properties.space == ColorSpace::BT709 {
+            YuvMatrix::BT709
+        } else {
+            YuvMatrix::BT601
+        };
+
+        let planes = self.planes();
+
+        let component = |plane: usize, line: usize, offset: usize| {
+            let plane = &planes[plane];
+
+            plane.data()[line * plane.line_size() + offset]
+        };
+
+        let packed = |r: usize, g: usize, b: usize, size: usize| {
+            let offset = x * size;
+
+            (
+                component(0, y, offset + r),
+                component(0, y, offset + g),
+                component(0, y, offset + b),
+            )
+        };
+
+        let planar = |cx: usize, cy: usize| {
+            yuv_to_rgb(
+                component(0, y, x),
+                component(1, cy, cx),
+                component(2, cy, cx),
+                full_range,
+                matrix,
+            )
+        };
+
+        let semi_planar = |u: usize, v: usize| {
+            let offset = (x >> 1) << 1;
+
+            yuv_to_rgb(
+                component(0, y, x),
+                component(1, y >> 1, offset + u),
+                component(1, y >> 1, offset + v),
+                full_range,
+                matrix,
+            )
+        };
+
+        let res = match name {
+            "rgb24" => packed(0, 1, 2, 3),
+            "bgr24" => packed(2, 1, 0, 3),
+            "rgba" | "rgb0" => packed(0, 1, 2, 4),
+            "bgra" | "bgr0" => packed(2, 1, 0, 4),
+            "argb" | "0rgb" => packed(1, 2, 3, 4),
+            "abgr" | "0bgr" => packed(3, 2, 1, 4),
+            "gray" => {
+                let v = component(0, y, x);
+
+                (v, v, v)
+            }
+            "yuv420p" | "yuvj420p" => planar(x >> 1, y >> 1),
+            "yuv422p" | "yuvj422p" => planar(x >> 1, y),
+            "yuv444p" | "yuvj444p" => planar(x, y),
+            "nv12" => semi_planar(0, 1),
+            "nv21" => semi_planar(1, 0),
+            name => {
+                return Err(Error::new(format!(
+                    "unsupported pixel format for sampling: {}",
+                    name
+                )))
+            }
+        };
+
+        Ok(res)
+    }
+
     /// Get raw pointer.
     pub(crate) fn as_ptr(&self) -> *const c_void {
         self.ptr
@@ -599,3 +1074,405 @@ impl Drop for VideoFrame {
 
 unsafe impl Send for VideoFrame {}
 unsafe impl Sync for VideoFrame {}
+
+/// Check that a QP table of a given length and stride covers all macroblocks
+/// of a picture with given dimensions.
+fn check_qp_table(width: usize, height: usize, len: usize, stride: usize) -> Result<(), Error> {
+    let mb_width = (width + 15) >> 4;
+    let mb_height = (height + 15) >> 4;
+
+    if mb_width == 0 || mb_height == 0 {
+        Err(Error::new("empty frame"))
+    } else if stride < mb_width {
+        Err(Error::new(
+            "QP table stride is lower than the number of macroblocks",
+        ))
+    } else if len < (mb_height - 1) * stride + mb_width {
+        Err(Error::new("QP table is too small"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Luma coefficients of a YUV color space.
+#[derive(Copy, Clone)]
+struct YuvMatrix {
+    kr: f32,
+    kb: f32,
+}
+
+impl YuvMatrix {
+    const BT601: Self = Self {
+        kr: 0.299,
+        kb: 0.114,
+    };
+
+    const BT709: Self = Self {
+        kr: 0.2126,
+        kb: 0.0722,
+    };
+}
+
+/// Convert a given YUV value into RGB using given coefficients.
+fn yuv_to_rgb(y: u8, u: u8, v: u8, full_range: bool, matrix: YuvMatrix) -> (u8, u8, u8) {
+    let (y, u, v) = if full_range {
+        (y as f32, u as f32 - 128.0, v as f32 - 128.0)
+    } else {
+        (
+            (y as f32 - 16.0) * 255.0 / 219.0,
+            (u as f32 - 128.0) * 255.0 / 224.0,
+            (v as f32 - 128.0) * 255.0 / 224.0,
+        )
+    };
+
+    let YuvMatrix { kr, kb } = matrix;
+
+    let kg = 1.0 - kr - kb;
+
+    let r = y + 2.0 * (1.0 - kr) * v;
+    let g = y - 2.0 * kb * (1.0 - kb) / kg * u - 2.0 * kr * (1.0 - kr) / kg * v;
+    let b = y + 2.0 * (1.0 - kb) * u;
+
+    let to_u8 = |c: f32| c.round().clamp(0.0, 255.0) as u8;
+
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        os::raw::{c_int, c_void},
+        time::Duration,
+    };
+
+    use super::{
+        check_qp_table, get_pixel_format, ColorPrimaries, ColorProperties, ColorRange, ColorSpace,
+        ColorTransferCharacteristic, Rect, TimeBase, VideoFrameMut,
+    };
+
+    #[test]
+    fn test_sample_black_yuv() {
+        let frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 4, 4).freeze();
+
+        assert_eq!(frame.sample_rgb(3, 3).unwrap(), (0, 0, 0));
+        assert!(frame.sample_rgb(4, 0).is_err());
+    }
+
+    #[test]
+    fn test_sample_full_range_yuv() {
+        // the black frame uses limited range levels (i.e. Y = 16), so they
+        // will appear as dark gray if the frame is tagged as full range
+        let frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 4, 4)
+            .freeze()
+            .with_color_properties(ColorProperties {
+                range: ColorRange::JPEG,
+                primaries: ColorPrimaries::UNSPECIFIED,
+                transfer: ColorTransferCharacteristic::UNSPECIFIED,
+                space: ColorSpace::UNSPECIFIED,
+            });
+
+        assert_eq!(frame.sample_rgb(1, 2).unwrap(), (16, 16, 16));
+    }
+
+    #[test]
+    fn test_sample_solid_rgb() {
+        let mut frame = VideoFrameMut::black(get_pixel_format("rgb24"), 4, 4);
+
+        for line in frame.planes_mut()[0].lines_mut() {
+            for pixel in line.chunks_exact_mut(3) {
+                pixel.copy_from_slice(&[10, 20, 30]);
+            }
+        }
+
+        let frame = frame.freeze();
+
+        assert_eq!(frame.sample_rgb(2, 1).unwrap(), (10, 20, 30));
+    }
+
+    #[test]
+    fn test_sample_bt709_yuv() {
+        let mut frame = VideoFrameMut::black(get_pixel_format("yuv444p"), 4, 4);
+
+        for (plane, value) in frame.planes_mut().iter_mut().zip(&[128, 128, 178]) {
+            for line in plane.lines_mut() {
+                line.fill(*value);
+            }
+        }
+
+        let frame = frame.freeze();
+
+        let properties = ColorProperties {
+            range: ColorRange::JPEG,
+            primaries: ColorPrimaries::UNSPECIFIED,
+            transfer: ColorTransferCharacteristic::UNSPECIFIED,
+            space: ColorSpace::UNSPECIFIED,
+        };
+
+        let frame = frame.with_color_properties(properties);
+
+        // BT.601 is used for untagged frames
+        assert_eq!(frame.sample_rgb(1, 1).unwrap(), (198, 92, 128));
+
+        let frame = frame.with_color_properties(ColorProperties {
+            space: ColorSpace::BT709,
+            ..properties
+        });
+
+        assert_eq!(frame.sample_rgb(1, 1).unwrap(), (207, 105, 128));
+    }
+
+    #[test]
+    fn test_frame_flags() {
+        let frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 4, 4).freeze();
+
+        assert!(!frame.is_hardware());
+        assert!(!frame.is_corrupt());
+        assert!(!frame.clone().is_hardware());
+    }
+
+    #[test]
+    fn test_hardware_frame() {
+        let frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 4, 4).freeze();
+
+        let ret = unsafe { ffw_frame_set_dummy_hw_frames_ctx(frame.ptr) };
+
+        assert_eq!(ret, 0);
+
+        assert!(frame.is_hardware());
+        assert!(frame.clone().is_hardware());
+    }
+
+    #[test]
+    fn test_corrupt_frame() {
+        let frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 4, 4).freeze();
+
+        unsafe { ffw_frame_set_corrupt(frame.ptr) }
+
+        assert!(frame.is_corrupt());
+        assert!(frame.clone().is_corrupt());
+    }
+
+    #[test]
+    fn test_regions_of_interest() {
+        let mut frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 16, 16).freeze();
+
+        assert!(frame.regions_of_interest().is_empty());
+
+        frame.add_roi(Rect::new(0, 0, 8, 8), -0.5).unwrap();
+        frame.add_roi(Rect::new(8, 8, 4, 4), 0.25).unwrap();
+
+        assert!(frame.add_roi(Rect::new(0, 0, 0, 8), 0.0).is_err());
+        assert!(frame.add_roi(Rect::new(0, 0, 8, 8), 2.0).is_err());
+
+        // the region edges must not overflow
+        assert!(frame.add_roi(Rect::new(usize::MAX, 0, 1, 1), 0.0).is_err());
+        assert!(frame
+            .add_roi(Rect::new(0, c_int::MAX as usize, 1, 1), 0.0)
+            .is_err());
+
+        let frame = frame.clone();
+
+        let expected = vec![(Rect::new(0, 0, 8, 8), -0.5), (Rect::new(8, 8, 4, 4), 0.25)];
+
+        assert_eq!(frame.regions_of_interest(), expected);
+    }
+
+    #[test]
+    fn test_caption_data() {
+        let mut frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 16, 16).freeze();
+
+        assert!(frame.caption_data().is_none());
+
+        frame.set_caption_data(&[0xfc, 0x94, 0x2c]);
+        frame.set_caption_data(&[0xfc, 0x80, 0x80]);
+
+        let mut frame = frame.clone();
+
+        assert_eq!(frame.caption_data(), Some(&[0xfc, 0x80, 0x80][..]));
+
+        frame.set_caption_data(&[]);
+
+        assert!(frame.caption_data().is_none());
+    }
+
+    #[test]
+    fn test_interlacing() {
+        let mut frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 16, 16).freeze();
+
+        assert!(!frame.is_interlaced());
+
+        frame.set_interlaced(true, true);
+
+        let mut frame = frame.clone();
+
+        assert!(frame.is_interlaced());
+        assert!(frame.top_field_first());
+
+        frame.set_interlaced(false, false);
+
+        assert!(!frame.is_interlaced());
+        assert!(!frame.top_field_first());
+    }
+
+    #[link(name = "ffwrapper_test", kind = "static")]
+    extern "C" {
+        fn ffw_frame_set_dummy_hw_frames_ctx(frame: *mut c_void) -> c_int;
+        fn ffw_frame_set_corrupt(frame: *mut c_void);
+        fn ffw_frame_set_repeat_pict(frame: *mut c_void, repeat_pict: c_int);
+    }
+
+    #[cfg(video_enc_params)]
+    #[link(name = "ffwrapper_test", kind = "static")]
+    extern "C" {
+        fn ffw_frame_get_video_enc_params_block_count(frame: *const c_void) -> c_int;
+        fn ffw_frame_get_video_enc_params_block(
+            frame: *const c_void,
+            index: c_int,
+            src_x: *mut c_int,
+            src_y: *mut c_int,
+            delta_qp: *mut c_int,
+        );
+    }
+
+    #[test]
+    fn test_qp_table_errors() {
+        let mut frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 32, 32).freeze();
+
+        // the stride is lower than the number of macroblocks in a row
+        assert_eq!(
+            frame.set_qp_table(&[0; 4], 1).unwrap_err().to_string(),
+            "QP table stride is lower than the number of macroblocks"
+        );
+
+        // the table does not cover the last macroblock
+        assert_eq!(
+            frame.set_qp_table(&[0; 4], 3).unwrap_err().to_string(),
+            "QP table is too small"
+        );
+
+        // frames with zero width or height cannot be allocated, so the check
+        // is tested directly
+        assert_eq!(
+            check_qp_table(0, 0, 16, 1).unwrap_err().to_string(),
+            "empty frame"
+        );
+        assert_eq!(
+            check_qp_table(16, 0, 16, 1).unwrap_err().to_string(),
+            "empty frame"
+        );
+
+        assert!(check_qp_table(17, 17, 4, 2).is_ok());
+        assert!(check_qp_table(17, 17, 3, 2).is_err());
+    }
+
+    #[cfg(video_enc_params)]
+    #[test]
+    fn test_qp_table() {
+        let mut frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 32, 32).freeze();
+
+        frame.set_qp_table(&[1, 2, 0, 3, 4], 3).unwrap();
+
+        let frame = frame.clone();
+
+        let count = unsafe { ffw_frame_get_video_enc_params_block_count(frame.ptr) };
+
+        assert_eq!(count, 4);
+
+        let blocks = (0..count)
+            .map(|index| {
+                let mut src_x = 0;
+                let mut src_y = 0;
+                let mut delta_qp = 0;
+
+                unsafe {
+                    ffw_frame_get_video_enc_params_block(
+                        frame.ptr,
+                        index,
+                        &mut src_x,
+                        &mut src_y,
+                        &mut delta_qp,
+                    );
+                }
+
+                (src_x, src_y, delta_qp)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(blocks, [(0, 0, 1), (16, 0, 2), (0, 16, 3), (16, 16, 4)]);
+    }
+
+    #[test]
+    fn test_repeat_pict() {
+        let frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 16, 16).freeze();
+
+        assert_eq!(frame.repeat_pict(), 0);
+
+        unsafe { ffw_frame_set_repeat_pict(frame.ptr, 1) }
+
+        assert_eq!(frame.repeat_pict(), 1);
+        assert_eq!(frame.clone().repeat_pict(), 1);
+    }
+
+    #[test]
+    fn test_color_properties() {
+        let properties = ColorProperties {
+            range: ColorRange::MPEG,
+            primaries: ColorPrimaries::BT709,
+            transfer: ColorTransferCharacteristic::BT709,
+            space: ColorSpace::BT709,
+        };
+
+        let frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 16, 16)
+            .freeze()
+            .with_color_properties(properties);
+
+        for frame in &[frame.clone(), frame] {
+            let properties = frame.color_properties();
+
+            assert_eq!(properties.range.name(), Some("tv"));
+            assert_eq!(properties.primaries.name(), Some("bt709"));
+            assert_eq!(properties.transfer.name(), Some("bt709"));
+            assert_eq!(properties.space.name(), Some("bt709"));
+        }
+    }
+
+    #[test]
+    fn test_opaque() {
+        let mut frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 16, 16).freeze();
+
+        assert_eq!(frame.opaque(), 0);
+
+        frame.set_opaque(u64::MAX - 1);
+
+        assert_eq!(frame.clone().opaque(), u64::MAX - 1);
+    }
+
+    #[test]
+    fn test_duration_rescaling() {
+        let frame = VideoFrameMut::black(get_pixel_format("yuv420p"), 16, 16)
+            .freeze()
+            .with_time_base(TimeBase::new(1, 25));
+
+        assert!(frame.duration().is_none());
+
+        let frame = frame
+            .with_duration(Duration::from_millis(40))
+            .with_time_base(TimeBase::new(1, 90_000));
+
+        assert_eq!(frame.raw_duration(), 3600);
+        assert_eq!(frame.duration(), Some(Duration::from_millis(40)));
+
+        let frame = frame
+            .with_raw_duration(1800)
+            .with_time_base(TimeBase::MICROSECONDS);
+
+        assert_eq!(frame.raw_duration(), 20_000);
+    }
+
+    #[test]
+    fn test_sample_unsupported_format() {
+        let frame = VideoFrameMut::black(get_pixel_format("yuv410p"), 4, 4).freeze();
+
+        assert!(frame.sample_rgb(0, 0).is_err());
+    }
+}
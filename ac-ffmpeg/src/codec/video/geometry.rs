@@ -0,0 +1,81 @@
+//! Geometry primitives.
+//!
+//! The `Display` implementations use the syntax expected by FFmpeg filter
+//! options.
+
+use std::fmt::{self, Display, Formatter};
+
+/// A point.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Point {
+    /// Horizontal coordinate.
+    pub x: usize,
+    /// Vertical coordinate.
+    pub y: usize,
+}
+
+impl Point {
+    /// Create a new point.
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Display for Point {
+    /// Format the point as `x:y`.
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}:{}", self.x, self.y)
+    }
+}
+
+/// A rectangle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rect {
+    /// Left edge.
+    pub x: usize,
+    /// Top edge.
+    pub y: usize,
+    /// Width.
+    pub w: usize,
+    /// Height.
+    pub h: usize,
+}
+
+impl Rect {
+    /// Create a new rectangle.
+    pub const fn new(x: usize, y: usize, w: usize, h: usize) -> Self {
+        Self { x, y, w, h }
+    }
+
+    /// Get the top left corner.
+    pub fn origin(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    /// Check if the rectangle is empty.
+    pub fn is_empty(&self) -> bool {
+        self.w == 0 || self.h == 0
+    }
+}
+
+impl Display for Rect {
+    /// Format the rectangle as `w:h:x:y` (i.e. the syntax of the crop
+    /// filter).
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}:{}:{}:{}", self.w, self.h, self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Point, Rect};
+
+    #[test]
+    fn test_display() {
+        let rect = Rect::new(10, 20, 640, 360);
+
+        assert_eq!(rect.to_string(), "640:360:10:20");
+        assert_eq!(rect.origin().to_string(), "10:20");
+        assert_eq!(Point::new(0, 5).to_string(), "0:5");
+    }
+}
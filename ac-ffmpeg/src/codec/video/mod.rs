@@ -1,6 +1,8 @@
 //! Video decoder/encoder.
 
+pub mod color;
 pub mod frame;
+pub mod geometry;
 pub mod scaler;
 
 use std::{ffi::CString, os::raw::c_void, ptr};
@@ -522,3 +524,46 @@ impl Drop for VideoEncoder {
 
 unsafe impl Send for VideoEncoder {}
 unsafe impl Sync for VideoEncoder {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{frame::get_pixel_format, VideoDecoder};
+
+    use crate::{
+        codec::{Decoder, VideoCodecParameters},
+        packet::PacketMut,
+        time::{TimeBase, Timestamp},
+    };
+
+    #[test]
+    fn test_decoded_frame_duration() {
+        let params =
+            VideoCodecParameters::for_raw_video(get_pixel_format("yuv420p"), 16, 16, (1, 1))
+                .unwrap();
+
+        let mut decoder = VideoDecoder::from_codec_parameters(&params)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let time_base = TimeBase::new(1, 90_000);
+
+        // one yuv420p picture with 40 ms duration in the 90 kHz time base
+        let packet = PacketMut::new(16 * 16 * 3 / 2)
+            .with_time_base(time_base)
+            .with_pts(Timestamp::new(0, time_base))
+            .with_raw_duration(3600)
+            .with_key_flag(true)
+            .freeze();
+
+        decoder.push(packet).unwrap();
+
+        let frame = decoder.take().unwrap().unwrap();
+
+        assert_eq!(frame.time_base().den(), 1_000_000);
+        assert_eq!(frame.raw_duration(), 40_000);
+        assert_eq!(frame.duration(), Some(Duration::from_millis(40)));
+    }
+}
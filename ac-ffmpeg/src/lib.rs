@@ -90,6 +90,23 @@ where
     }
 }
 
+/// Get description of a given FFmpeg error code (e.g. a negative value
+/// returned by a native FFmpeg function).
+pub fn error_string(code: i32) -> String {
+    let mut buffer = [0u8; 256];
+
+    let buffer_ptr = buffer.as_mut_ptr();
+    let buffer_len = buffer.len();
+
+    unsafe {
+        ffw_error_get_error_string(code as _, buffer_ptr as _, buffer_len as _);
+
+        CStr::from_ptr(buffer.as_ptr() as _)
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
 /// Error variants.
 #[derive(Debug, Clone)]
 enum ErrorVariant {
@@ -137,25 +154,26 @@ impl Error {
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         match &self.variant {
-            ErrorVariant::FFmpeg(code) => {
-                let mut buffer = [0u8; 256];
-
-                let buffer_ptr = buffer.as_mut_ptr();
-                let buffer_len = buffer.len();
-
-                let msg = unsafe {
-                    ffw_error_get_error_string(*code, buffer_ptr as _, buffer_len as _);
-
-                    CStr::from_ptr(buffer.as_ptr() as _)
-                        .to_str()
-                        .expect("UTF-8 encoded error string expected")
-                };
-
-                write!(f, "{}", msg)
-            }
+            ErrorVariant::FFmpeg(code) => write!(f, "{}", error_string(*code)),
             ErrorVariant::Other(msg) => write!(f, "{}", msg),
         }
     }
 }
 
 impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_string, ffw_error_from_posix};
+
+    #[test]
+    fn test_error_string() {
+        // EINVAL
+        let code = unsafe { ffw_error_from_posix(22) };
+
+        assert_eq!(error_string(code), "Invalid argument");
+
+        // unknown error codes should still have some description
+        assert!(!error_string(-123_456).is_empty());
+    }
+}
@@ -9,7 +9,7 @@ use std::{
     time::Duration,
 };
 
-use crate::time::{TimeBase, Timestamp};
+use crate::time::{rescale_duration, TimeBase, Timestamp};
 
 extern "C" {
     fn ffw_packet_alloc() -> *mut c_void;
@@ -77,15 +77,17 @@ impl PacketMut {
         self.time_base
     }
 
-    /// Set packet time base. (This will rescale the current timestamps into a
-    /// given time base.)
+    /// Set packet time base. (This will rescale the current timestamps and
+    /// duration into a given time base.)
     pub fn with_time_base(mut self, time_base: TimeBase) -> Self {
         let new_pts = self.pts().with_time_base(time_base);
         let new_dts = self.dts().with_time_base(time_base);
+        let new_duration = rescale_duration(self.raw_duration(), self.time_base, time_base);
 
         unsafe {
             ffw_packet_set_pts(self.ptr, new_pts.timestamp());
             ffw_packet_set_dts(self.ptr, new_dts.timestamp());
+            ffw_packet_set_duration(self.ptr, new_duration);
         }
 
         self.time_base = time_base;
@@ -282,15 +284,17 @@ impl Packet {
         self.time_base
     }
 
-    /// Set packet time base. (This will rescale the current timestamps into a
-    /// given time base.)
+    /// Set packet time base. (This will rescale the current timestamps and
+    /// duration into a given time base.)
     pub fn with_time_base(mut self, time_base: TimeBase) -> Self {
         let new_pts = self.pts().with_time_base(time_base);
         let new_dts = self.dts().with_time_base(time_base);
+        let new_duration = rescale_duration(self.raw_duration(), self.time_base, time_base);
 
         unsafe {
             ffw_packet_set_pts(self.ptr, new_pts.timestamp());
             ffw_packet_set_dts(self.ptr, new_dts.timestamp());
+            ffw_packet_set_duration(self.ptr, new_duration);
         }
 
         self.time_base = time_base;
@@ -471,3 +475,31 @@ impl Drop for Packet {
 
 unsafe impl Send for Packet {}
 unsafe impl Sync for Packet {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::PacketMut;
+
+    use crate::time::{TimeBase, Timestamp};
+
+    #[test]
+    fn test_duration_rescaling() {
+        let time_base = TimeBase::new(1, 90_000);
+
+        let packet = PacketMut::new(0)
+            .with_time_base(time_base)
+            .with_pts(Timestamp::new(9000, time_base))
+            .with_raw_duration(3600)
+            .with_time_base(TimeBase::MICROSECONDS);
+
+        assert_eq!(packet.pts().timestamp(), 100_000);
+        assert_eq!(packet.raw_duration(), 40_000);
+
+        let packet = packet.freeze().with_time_base(time_base);
+
+        assert_eq!(packet.raw_duration(), 3600);
+        assert_eq!(packet.duration(), Some(Duration::from_millis(40)));
+    }
+}
@@ -203,6 +203,18 @@ impl Timestamp {
     }
 }
 
+/// Rescale a given raw duration. Unknown (i.e. zero or negative) durations
+/// are kept as they are.
+pub(crate) fn rescale_duration(duration: i64, from: TimeBase, to: TimeBase) -> i64 {
+    if duration > 0 {
+        Timestamp::new(duration, from)
+            .with_time_base(to)
+            .timestamp()
+    } else {
+        duration
+    }
+}
+
 impl Debug for Timestamp {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         if let Some(millis) = self.as_millis() {
@@ -318,7 +330,7 @@ impl PartialOrd for Timestamp {
 mod tests {
     use std::time::Duration;
 
-    use super::{TimeBase, Timestamp};
+    use super::{rescale_duration, TimeBase, Timestamp};
 
     #[test]
     fn test_duration_add() {
@@ -374,4 +386,15 @@ mod tests {
 
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn test_rescale_duration() {
+        let from = TimeBase::new(1, 90_000);
+
+        assert_eq!(rescale_duration(3600, from, TimeBase::MICROSECONDS), 40_000);
+
+        // unknown durations are kept as they are
+        assert_eq!(rescale_duration(0, from, TimeBase::MICROSECONDS), 0);
+        assert_eq!(rescale_duration(-1, from, TimeBase::MICROSECONDS), -1);
+    }
 }